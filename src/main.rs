@@ -1,13 +1,18 @@
 use std::fmt;
 
-/// A struct to represent elements of the field \( \mathbb{F}_{5^2} \)
+use clap::{Parser, Subcommand};
+
+/// A struct to represent elements of the prime-field extension \( \mathbb{F}_{p^2} = \mathbb{F}_p[t]/(t^2 - n) \),
+/// where `p` is the field characteristic and `n` is a quadratic non-residue mod `p`.
 #[derive(Clone, Copy, Debug, PartialEq)]
-struct F5x2 {
+struct Fp2 {
+    p: u8, // Field characteristic
+    n: u8, // Non-residue such that t^2 ≡ n (mod p)
     a: u8, // Coefficient for 1
     b: u8, // Coefficient for t
 }
 
-impl fmt::Display for F5x2 {
+impl fmt::Display for Fp2 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match (self.a, self.b) {
             (0, 0) => write!(f, "0"),
@@ -18,66 +23,93 @@ impl fmt::Display for F5x2 {
     }
 }
 
-impl F5x2 {
-    /// Create a new field element
-    fn new(a: u8, b: u8) -> Self {
-        F5x2 { a: a % 5, b: b % 5 }
+impl Fp2 {
+    /// Create a new element of \( \mathbb{F}_{p^2} \) with non-residue `n`
+    fn new(p: u8, n: u8, a: u8, b: u8) -> Self {
+        Fp2 {
+            p,
+            n: n % p,
+            a: a % p,
+            b: b % p,
+        }
     }
 
-    /// Add two elements of \( \mathbb{F}_{5^2} \)
-    fn add(self, other: F5x2) -> F5x2 {
-        F5x2 {
-            a: (self.a + other.a) % 5,
-            b: (self.b + other.b) % 5,
-        }
+    /// Create a new element in the same field as `self` (same `p` and `n`)
+    fn like(self, a: u8, b: u8) -> Fp2 {
+        Fp2::new(self.p, self.n, a, b)
     }
 
-    /// Multiply two elements of \( \mathbb{F}_{5^2} \)
-    fn mul(self, other: F5x2) -> F5x2 {
-        let a = self.a as i16;
-        let b = self.b as i16;
-        let c = other.a as i16;
-        let d = other.b as i16;
+    /// Check whether this is the zero element
+    fn is_zero(self) -> bool {
+        self.a == 0 && self.b == 0
+    }
+
+    /// Add two elements of \( \mathbb{F}_{p^2} \)
+    fn add(self, other: Fp2) -> Fp2 {
+        // i64 avoids overflowing u8 for primes past roughly 128 (e.g. 150 + 150).
+        let p = self.p as i64;
+        let new_a = (self.a as i64 + other.a as i64).rem_euclid(p);
+        let new_b = (self.b as i64 + other.b as i64).rem_euclid(p);
+
+        self.like(new_a as u8, new_b as u8)
+    }
+
+    /// Multiply two elements of \( \mathbb{F}_{p^2} \)
+    fn mul(self, other: Fp2) -> Fp2 {
+        // i64 keeps every intermediate product in range for the full u8 modulus space
+        // (up to 255), where i16 would overflow on primes past roughly 128 (`ad + bc`
+        // and `ac + n*bd` each sum two products of up to (p - 1)^2).
+        let p = self.p as i64;
+        let n = self.n as i64;
+        let a = self.a as i64;
+        let b = self.b as i64;
+        let c = other.a as i64;
+        let d = other.b as i64;
 
         // Polynomial multiplication: (a + bt) * (c + dt)
-        let ac = (a * c) % 5;
-        let bd = (b * d) % 5;
-        let ad_bc = (a * d + b * c) % 5;
+        let ac = (a * c) % p;
+        let bd = (b * d) % p;
+        let ad_bc = (a * d + b * c) % p;
 
-        // Reduction modulo t^2 + 2, where t^2 ≡ 3 (mod 5)
-        let new_a = (ac + 3 * bd) % 5;
-        let new_b = ad_bc % 5;
+        // Reduction modulo t^2 - n, where t^2 ≡ n (mod p)
+        let new_a = (ac + n * bd).rem_euclid(p);
+        let new_b = ad_bc.rem_euclid(p);
 
-        F5x2::new(new_a as u8, new_b as u8)
+        self.like(new_a as u8, new_b as u8)
     }
 
-    /// Subtract two elements of \( \mathbb{F}_{5^2} \)
-    fn sub(self, other: F5x2) -> F5x2 {
-        F5x2 {
-            a: (self.a + 5 - other.a) % 5,
-            b: (self.b + 5 - other.b) % 5,
-        }
+    /// Subtract two elements of \( \mathbb{F}_{p^2} \)
+    fn sub(self, other: Fp2) -> Fp2 {
+        // i64 keeps the difference signed (e.g. 0 - 150), so `rem_euclid` can wrap it
+        // back into range where u8 subtraction would underflow.
+        let p = self.p as i64;
+        let new_a = (self.a as i64 - other.a as i64).rem_euclid(p);
+        let new_b = (self.b as i64 - other.b as i64).rem_euclid(p);
+
+        self.like(new_a as u8, new_b as u8)
     }
 
-    /// Divide two elements of \( \mathbb{F}_{5^2} \)
-    fn div(self, other: F5x2) -> F5x2 {
+    /// Divide two elements of \( \mathbb{F}_{p^2} \)
+    fn div(self, other: Fp2) -> Fp2 {
         let inv = other.inverse();
         self.mul(inv)
     }
 
-    /// Find the inverse of an element in \( \mathbb{F}_{5^2} \)
-    fn inverse(self) -> F5x2 {
-        // Compute the inverse using the formula: (a + bt)^-1 = (a - bt) / (a^2 - 3b^2)
-        let a = self.a as i16;
-        let b = self.b as i16;
+    /// Find the inverse of an element in \( \mathbb{F}_{p^2} \)
+    fn inverse(self) -> Fp2 {
+        // Compute the inverse using the formula: (a + bt)^-1 = (a - bt) / (a^2 - n*b^2)
+        let p = self.p as i64;
+        let n = self.n as i64;
+        let a = self.a as i64;
+        let b = self.b as i64;
 
-        let denominator = (a * a - 3 * b * b).rem_euclid(5) as u8;
-        let inv_denominator = Self::mod_inverse(denominator, 5);
+        let denominator = (a * a - n * b * b).rem_euclid(p) as u8;
+        let inv_denominator = Self::mod_inverse(denominator, self.p);
 
-        let new_a = (a * inv_denominator as i16).rem_euclid(5) as u8;
-        let new_b = (5 - (b * inv_denominator as i16).rem_euclid(5)) as u8;
+        let new_a = (a * inv_denominator as i64).rem_euclid(p) as u8;
+        let new_b = (self.p - (b * inv_denominator as i64).rem_euclid(p) as u8) % self.p;
 
-        F5x2::new(new_a, new_b)
+        self.like(new_a, new_b)
     }
 
     /// Compute modular inverse using extended Euclidean algorithm
@@ -91,15 +123,21 @@ impl F5x2 {
     }
 }
 
+/// Construct an element of \( \mathbb{F}_{5^2} = \mathbb{F}_5[t]/(t^2 - 3) \), the field used
+/// throughout the examples in this crate.
+fn f5x2(a: u8, b: u8) -> Fp2 {
+    Fp2::new(5, 3, a, b)
+}
+
 /// A struct to represent a point on the elliptic curve
 #[derive(Clone, Copy, Debug)]
 struct Point {
-    x: Option<F5x2>,
-    y: Option<F5x2>,
+    x: Option<Fp2>,
+    y: Option<Fp2>,
 }
 
 impl Point {
-    fn new(x: Option<F5x2>, y: Option<F5x2>) -> Self {
+    fn new(x: Option<Fp2>, y: Option<Fp2>) -> Self {
         Point { x, y }
     }
 
@@ -109,55 +147,420 @@ impl Point {
     }
 }
 
-fn point_add(p: Point, q: Point, a: F5x2) -> Point {
-    if p.is_infinity() {
-        return q;
+/// A point on the elliptic curve in projective (X:Y:Z) coordinates. The point
+/// at infinity is represented as (0:1:0), and every affine point (x, y) maps to
+/// (x:y:1), avoiding the division needed by affine addition.
+#[derive(Clone, Copy, Debug)]
+struct ProjectivePoint {
+    x: Fp2,
+    y: Fp2,
+    z: Fp2,
+}
+
+impl ProjectivePoint {
+    fn new(x: Fp2, y: Fp2, z: Fp2) -> Self {
+        ProjectivePoint { x, y, z }
+    }
+
+    /// The point at infinity (0:1:0), in the field of `field`
+    fn identity(field: Fp2) -> Self {
+        ProjectivePoint::new(field.like(0, 0), field.like(1, 0), field.like(0, 0))
+    }
+
+    /// Lift an affine `Point` to projective coordinates
+    fn from_affine(p: Point, field: Fp2) -> Self {
+        match (p.x, p.y) {
+            (Some(x), Some(y)) => ProjectivePoint::new(x, y, field.like(1, 0)),
+            _ => ProjectivePoint::identity(field),
+        }
+    }
+
+    /// Recover the affine `Point`, dividing through by `Z`
+    fn to_affine(self) -> Point {
+        if self.z == self.z.like(0, 0) {
+            return Point::new(None, None);
+        }
+        let z_inv = self.z.inverse();
+        Point::new(Some(self.x.mul(z_inv)), Some(self.y.mul(z_inv)))
+    }
+}
+
+/// Complete, branch-free point addition on the short Weierstrass curve
+/// \( y^2 = x^3 + ax + b \), using the Renes–Costello–Batina (2015) formulas
+/// (Algorithm 1 of "Complete addition formulas for prime order elliptic curves").
+/// Unlike naive affine addition, this single straight-line sequence of field
+/// operations is correct for every input on a curve with no point of order 2,
+/// including doubling (P = Q), P = -Q, and either operand being the point at
+/// infinity. On a curve that has a point of order 2, a pair whose difference
+/// P - Q has order 2 yields (0:0:0); `Curve::add` handles that case.
+fn projective_add(p: ProjectivePoint, q: ProjectivePoint, a: Fp2, b3: Fp2) -> ProjectivePoint {
+    let (x1, y1, z1) = (p.x, p.y, p.z);
+    let (x2, y2, z2) = (q.x, q.y, q.z);
+
+    let t0 = x1.mul(x2);
+    let t1 = y1.mul(y2);
+    let t2 = z1.mul(z2);
+    let t3 = x1.add(y1).mul(x2.add(y2));
+    let t3 = t3.sub(t0).sub(t1);
+    let t4 = x1.add(z1).mul(x2.add(z2));
+    let t4 = t4.sub(t0).sub(t2);
+    let t5 = y1.add(z1).mul(y2.add(z2));
+    let x3 = t1.add(t2);
+    let t5 = t5.sub(x3);
+    let z3 = a.mul(t4);
+    let x3 = b3.mul(t2);
+    let z3 = x3.add(z3);
+    let x3 = t1.sub(z3);
+    let z3 = t1.add(z3);
+    let y3 = x3.mul(z3);
+    let t1 = t0.add(t0).add(t0);
+    let t2 = a.mul(t2);
+    let t4 = b3.mul(t4);
+    let t1 = t1.add(t2);
+    let t2 = t0.sub(t2);
+    let t2 = a.mul(t2);
+    let t4 = t4.add(t2);
+    let t0 = t1.mul(t4);
+    let y3 = y3.add(t0);
+    let t0 = t5.mul(t4);
+    let x3 = t3.mul(x3);
+    let x3 = x3.sub(t0);
+    let t0 = t3.mul(t1);
+    let z3 = t5.mul(z3);
+    let z3 = z3.add(t0);
+
+    ProjectivePoint::new(x3, y3, z3)
+}
+
+/// A short Weierstrass elliptic curve \( y^2 = x^3 + ax + b \) over \( \mathbb{F}_{p^2} \),
+/// carrying both coefficients so callers no longer have to pass `a` around by hand
+/// and so point addition can route through the complete projective formulas above.
+#[derive(Clone, Copy, Debug)]
+struct Curve {
+    a: Fp2,
+    b: Fp2,
+}
+
+impl Curve {
+    fn new(a: Fp2, b: Fp2) -> Self {
+        Curve { a, b }
+    }
+
+    /// Check whether `p` satisfies \( y^2 = x^3 + ax + b \). The point at infinity
+    /// is always considered on the curve.
+    fn is_on_curve(&self, p: Point) -> bool {
+        let (x, y) = match (p.x, p.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return true,
+        };
+
+        let lhs = y.mul(y);
+        let rhs = x.mul(x).mul(x).add(self.a.mul(x)).add(self.b);
+
+        lhs == rhs
+    }
+
+    /// Add two points on this curve via the complete Renes-Costello-Batina
+    /// projective formulas, which (unlike a naive affine formula) need no case
+    /// split for doubling, negation, or the identity. The one output they cannot
+    /// give, (0:0:0) when P - Q has order 2, falls back to the affine chord rule.
+    fn add(&self, p: Point, q: Point) -> Point {
+        let b3 = self.b.add(self.b).add(self.b);
+        let pp = ProjectivePoint::from_affine(p, self.a);
+        let qq = ProjectivePoint::from_affine(q, self.a);
+        let r = projective_add(pp, qq, self.a, b3);
+        if !(r.x.is_zero() && r.y.is_zero() && r.z.is_zero()) {
+            return r.to_affine();
+        }
+        // Here P - Q has order 2, so P != Q and no doubling is needed.
+        let (x1, y1, x2, y2) = match (p.x, p.y, q.x, q.y) {
+            (Some(x1), Some(y1), Some(x2), Some(y2)) => (x1, y1, x2, y2),
+            _ if p.is_infinity() => return q,
+            _ => return p,
+        };
+        if x1 == x2 {
+            return Point::new(None, None);
+        }
+        let l = y2.sub(y1).div(x2.sub(x1));
+        let x3 = l.mul(l).sub(x1).sub(x2);
+        Point::new(Some(x3), Some(l.mul(x1.sub(x3)).sub(y1)))
+    }
+
+    /// Compute the scalar multiple \( k \cdot P \) of a point using the
+    /// left-to-right double-and-add algorithm.
+    fn scalar_mul(&self, p: Point, k: u64) -> Point {
+        let mut acc = Point::new(None, None); // Point at infinity
+
+        for i in (0..64).rev() {
+            acc = self.add(acc, acc);
+            if (k >> i) & 1 == 1 {
+                acc = self.add(acc, p);
+            }
+        }
+
+        acc
+    }
+
+    /// Construct a point on this curve from its affine coordinates, returning
+    /// `None` if the point does not satisfy \( y^2 = x^3 + ax + b \).
+    fn new_point(&self, x: Fp2, y: Fp2) -> Option<Point> {
+        let p = Point::new(Some(x), Some(y));
+        if self.is_on_curve(p) {
+            Some(p)
+        } else {
+            None
+        }
     }
-    if q.is_infinity() {
-        return p;
+
+    /// Enumerate every point on this curve over \( \mathbb{F}_{5^2} \), including the
+    /// point at infinity, by brute-forcing all (x, y) pairs.
+    fn points(&self) -> Vec<Point> {
+        let mut pts = vec![Point::new(None, None)];
+
+        for xa in 0..5 {
+            for xb in 0..5 {
+                for ya in 0..5 {
+                    for yb in 0..5 {
+                        if let Some(p) = self.new_point(f5x2(xa, xb), f5x2(ya, yb)) {
+                            pts.push(p);
+                        }
+                    }
+                }
+            }
+        }
+
+        pts
     }
 
-    let (x1, y1) = (p.x.unwrap(), p.y.unwrap());
-    let (x2, y2) = (q.x.unwrap(), q.y.unwrap());
+    /// The order of `p`: the smallest positive `k` with \( k \cdot P = \infty \)
+    fn order_of(&self, p: Point) -> u64 {
+        let mut acc = p;
+        let mut k: u64 = 1;
+
+        while !acc.is_infinity() {
+            acc = self.add(acc, p);
+            k += 1;
+        }
 
-    if x1 == x2 && y1 != y2 {
-        return Point::new(None, None); // Point at infinity
+        k
     }
+}
 
-    let lambda = if x1 == x2 && y1 == y2 {
-        // Point doubling
-        let numerator = x1.mul(x1).mul(F5x2::new(3, 0)).add(a);
-        let denominator = y1.mul(F5x2::new(2, 0));
-        numerator.div(denominator)
-    } else {
-        // Point addition
-        let numerator = y2.sub(y1);
-        let denominator = x2.sub(x1);
-        numerator.div(denominator)
-    };
+/// Field and curve arithmetic over F_{5^2}
+#[derive(Parser)]
+#[command(name = "ecc", about = "Field and curve arithmetic over F_{5^2}")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
 
-    let x3 = lambda.mul(lambda).sub(x1).sub(x2);
-    let y3 = lambda.mul(x1.sub(x3)).sub(y1);
+#[derive(Subcommand)]
+enum Command {
+    /// Add two field elements, each written as `a+bt`
+    Add { x: String, y: String },
+    /// Multiply two field elements, each written as `a+bt`
+    Mul { x: String, y: String },
+    /// Subtract two field elements, each written as `a+bt`
+    Sub { x: String, y: String },
+    /// Divide two field elements, each written as `a+bt`
+    Div { x: String, y: String },
+    /// Invert a field element, written as `a+bt`
+    Inverse { x: String },
+    /// Add two points (each `x,y` or `inf`) on the curve y^2 = x^3 + ax + b
+    PointAdd {
+        a: String,
+        b: String,
+        p: String,
+        q: String,
+    },
+    /// Compute k*P on the curve y^2 = x^3 + ax + b
+    ScalarMul {
+        a: String,
+        b: String,
+        p: String,
+        k: u64,
+    },
+    /// Check whether a point (`x,y` or `inf`) lies on the curve y^2 = x^3 + ax + b
+    OnCurve { a: String, b: String, p: String },
+    /// List every point on the curve y^2 = x^3 + ax + b over F_{5^2}
+    Points { a: String, b: String },
+    /// Compute the order of a point (`x,y` or `inf`) on the curve y^2 = x^3 + ax + b
+    Order { a: String, b: String, p: String },
+}
 
-    Point::new(Some(x3), Some(y3))
+/// Parse a field element written as `a+bt`, `a`, or `bt` (e.g. `2+3t`) into F_{5^2}
+fn parse_fp2(s: &str) -> Fp2 {
+    let s = s.trim();
+    match s.find('t') {
+        Some(t_pos) => {
+            let rest = s[..t_pos].trim();
+            match rest.rfind('+') {
+                Some(plus_pos) => {
+                    let a: u8 = rest[..plus_pos].trim().parse().expect("invalid `a` coefficient");
+                    let b: u8 = rest[plus_pos + 1..].trim().parse().expect("invalid `b` coefficient");
+                    f5x2(a, b)
+                }
+                None if rest.is_empty() => f5x2(0, 1),
+                None => f5x2(0, rest.parse().expect("invalid `b` coefficient")),
+            }
+        }
+        None => f5x2(s.parse().expect("invalid `a` coefficient"), 0),
+    }
+}
+
+/// Parse a point written as `x,y` or `inf` for the point at infinity
+fn parse_point(s: &str) -> Point {
+    let s = s.trim();
+    if s.eq_ignore_ascii_case("inf") {
+        return Point::new(None, None);
+    }
+    let (x, y) = s.split_once(',').expect("point must be `x,y` or `inf`");
+    Point::new(Some(parse_fp2(x)), Some(parse_fp2(y)))
+}
+
+fn print_point(p: Point) {
+    match (p.x, p.y) {
+        (Some(x), Some(y)) => println!("({}, {})", x, y),
+        _ => println!("Point at Infinity"),
+    }
 }
 
 fn main() {
-    // Example: Add two points on the curve
-    let a = F5x2::new(1, 0); // Coefficient a = 1
-    let _b = F5x2::new(1, 0); // Coefficient b = 1
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Add { x, y } => println!("{}", parse_fp2(&x).add(parse_fp2(&y))),
+        Command::Mul { x, y } => println!("{}", parse_fp2(&x).mul(parse_fp2(&y))),
+        Command::Sub { x, y } => println!("{}", parse_fp2(&x).sub(parse_fp2(&y))),
+        Command::Div { x, y } => {
+            let y = parse_fp2(&y);
+            if y.is_zero() {
+                eprintln!("error: division by zero is undefined in F_{{p^2}}");
+                std::process::exit(1);
+            }
+            println!("{}", parse_fp2(&x).div(y));
+        }
+        Command::Inverse { x } => {
+            let x = parse_fp2(&x);
+            if x.is_zero() {
+                eprintln!("error: 0 has no multiplicative inverse in F_{{p^2}}");
+                std::process::exit(1);
+            }
+            println!("{}", x.inverse());
+        }
+        Command::PointAdd { a, b, p, q } => {
+            let curve = Curve::new(parse_fp2(&a), parse_fp2(&b));
+            print_point(curve.add(parse_point(&p), parse_point(&q)));
+        }
+        Command::ScalarMul { a, b, p, k } => {
+            let curve = Curve::new(parse_fp2(&a), parse_fp2(&b));
+            print_point(curve.scalar_mul(parse_point(&p), k));
+        }
+        Command::OnCurve { a, b, p } => {
+            let curve = Curve::new(parse_fp2(&a), parse_fp2(&b));
+            println!("{}", curve.is_on_curve(parse_point(&p)));
+        }
+        Command::Points { a, b } => {
+            let curve = Curve::new(parse_fp2(&a), parse_fp2(&b));
+            for p in curve.points() {
+                print_point(p);
+            }
+        }
+        Command::Order { a, b, p } => {
+            let curve = Curve::new(parse_fp2(&a), parse_fp2(&b));
+            println!("{}", curve.order_of(parse_point(&p)));
+        }
+    }
+}
 
-    let p1 = Point::new(Some(F5x2::new(1, 2)), Some(F5x2::new(4, 4)));
-    let p2 = Point::new(Some(F5x2::new(1, 2)), Some(F5x2::new(4, 4)));
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let p3 = point_add(p1, p2, a);
+    #[test]
+    fn fp2_mul_does_not_overflow_near_u8_max() {
+        // p = 251 is prime; with i16 intermediates this overflowed before the fix
+        // (250 * 250 exceeds i16::MAX).
+        let x = Fp2::new(251, 2, 250, 250);
+        let y = Fp2::new(251, 2, 250, 250);
+        let z = x.mul(y);
+        assert!(z.a < 251 && z.b < 251);
+    }
+
+    #[test]
+    fn fp2_add_and_sub_do_not_overflow_near_u8_max() {
+        // p = 251 is prime; `150 + 150` and `0 - 150` both overflow plain u8 arithmetic.
+        let x = Fp2::new(251, 2, 150, 150);
+        let y = Fp2::new(251, 2, 150, 150);
+
+        let sum = x.add(y);
+        assert_eq!(sum.a, ((150u16 + 150) % 251) as u8);
+        assert_eq!(sum.b, ((150u16 + 150) % 251) as u8);
 
-    println!("P1: ({}, {})", p1.x.unwrap(), p1.y.unwrap());
-    println!("P2: ({}, {})", p2.x.unwrap(), p2.y.unwrap());
+        let zero = Fp2::new(251, 2, 0, 0);
+        let diff = zero.sub(x);
+        assert_eq!(diff.a, 251 - 150);
+        assert_eq!(diff.b, 251 - 150);
+    }
+
+    #[test]
+    fn fp2_inverse_round_trip_f11x2() {
+        // 2 is a quadratic non-residue mod 11, so t^2 ≡ 2 defines F_{11^2}.
+        let one = Fp2::new(11, 2, 1, 0);
+        for a in 0..11 {
+            for b in 0..11 {
+                if a == 0 && b == 0 {
+                    continue;
+                }
+                let x = Fp2::new(11, 2, a, b);
+                assert_eq!(x.mul(x.inverse()), one);
+            }
+        }
+    }
+
+    #[test]
+    fn curve_add_doubles_a_2_torsion_point_to_infinity() {
+        // y^2 = x^3 + x over F_{5^2}: (0, 0) satisfies 0 = 0 and has y = 0, so
+        // naive affine doubling divides by y1*2 = 0 here instead of returning
+        // the point at infinity.
+        let curve = Curve::new(f5x2(1, 0), f5x2(0, 0));
+        let p = Point::new(Some(f5x2(0, 0)), Some(f5x2(0, 0)));
+        assert!(curve.is_on_curve(p));
+        assert!(curve.add(p, p).is_infinity());
+    }
+
+    #[test]
+    fn curve_add_identity_is_neutral() {
+        let curve = Curve::new(f5x2(1, 0), f5x2(1, 0));
+        let p = Point::new(Some(f5x2(1, 2)), Some(f5x2(4, 4)));
+        let inf = Point::new(None, None);
+        assert_eq!(curve.add(inf, p).x, p.x);
+        assert_eq!(curve.add(inf, p).y, p.y);
+        assert_eq!(curve.add(p, inf).x, p.x);
+        assert_eq!(curve.add(p, inf).y, p.y);
+    }
+
+    #[test]
+    fn new_point_rejects_points_off_the_curve() {
+        let curve = Curve::new(f5x2(1, 0), f5x2(1, 0));
+        assert!(curve.new_point(f5x2(1, 2), f5x2(4, 4)).is_some());
+        assert!(curve.new_point(f5x2(1, 2), f5x2(0, 0)).is_none());
+    }
+
+    #[test]
+    fn points_enumerates_the_group_and_order_of_matches_it() {
+        let curve = Curve::new(f5x2(1, 0), f5x2(1, 0));
+        let pts = curve.points();
+
+        // The point at infinity and every affine point found must lie on the curve.
+        assert!(pts.iter().any(Point::is_infinity));
+        assert!(pts.iter().all(|&p| curve.is_on_curve(p)));
+
+        for &p in pts.iter().filter(|p| !p.is_infinity()) {
+            let order = curve.order_of(p);
+            assert!(curve.scalar_mul(p, order).is_infinity());
+        }
 
-    if p3.is_infinity() {
-        println!("P1 + P2 = Point at Infinity");
-    } else {
-        println!("P1 + P2: ({}, {})", p3.x.unwrap(), p3.y.unwrap());
+        assert_eq!(curve.order_of(Point::new(None, None)), 1);
     }
 }